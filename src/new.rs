@@ -1,6 +1,154 @@
+use std::cmp;
 use std::io;
+use std::mem;
+use std::slice;
 
-use ByteOrder;
+use {BigEndian, ByteOrder, LittleEndian};
+
+/// A byte order that can be chosen at runtime, e.g. after reading a format
+/// marker from a file, rather than fixed at compile time via a `ByteOrder`
+/// type parameter.
+///
+/// This lets a parser read a format marker into a variable and then drive
+/// all subsequent number reads from that value, instead of duplicating its
+/// read logic across two generic branches.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Endianness {
+    /// Big endian, as produced by `BigEndian`.
+    Big,
+    /// Little endian, as produced by `LittleEndian`.
+    Little,
+}
+
+impl Endianness {
+    /// The host's native byte order.
+    #[cfg(target_endian = "big")]
+    pub const NATIVE: Endianness = Endianness::Big;
+
+    /// The host's native byte order.
+    #[cfg(target_endian = "little")]
+    pub const NATIVE: Endianness = Endianness::Little;
+
+    /// Network byte order, which is always big endian.
+    pub const NETWORK: Endianness = Endianness::Big;
+}
+
+/// Extends `ByteOrder` with in-place endianness correction for whole
+/// slices, used by the `*_into`/`*_from` slice methods below.
+///
+/// Unlike converting element-by-element, `from_slice_*` swaps bytes in
+/// place only when `T` disagrees with the host's native endianness, which
+/// compiles away entirely for the matching endianness.
+pub trait ByteOrderExt: ByteOrder {
+    fn from_slice_u16(slice: &mut [u16]);
+    fn from_slice_i16(slice: &mut [i16]);
+    fn from_slice_u32(slice: &mut [u32]);
+    fn from_slice_i32(slice: &mut [i32]);
+    fn from_slice_u64(slice: &mut [u64]);
+    fn from_slice_i64(slice: &mut [i64]);
+    fn from_slice_f32(slice: &mut [f32]);
+    fn from_slice_f64(slice: &mut [f64]);
+}
+
+impl ByteOrderExt for BigEndian {
+    fn from_slice_u16(slice: &mut [u16]) {
+        if cfg!(target_endian = "little") {
+            for x in slice { *x = x.swap_bytes(); }
+        }
+    }
+    fn from_slice_i16(slice: &mut [i16]) {
+        if cfg!(target_endian = "little") {
+            for x in slice { *x = x.swap_bytes(); }
+        }
+    }
+    fn from_slice_u32(slice: &mut [u32]) {
+        if cfg!(target_endian = "little") {
+            for x in slice { *x = x.swap_bytes(); }
+        }
+    }
+    fn from_slice_i32(slice: &mut [i32]) {
+        if cfg!(target_endian = "little") {
+            for x in slice { *x = x.swap_bytes(); }
+        }
+    }
+    fn from_slice_u64(slice: &mut [u64]) {
+        if cfg!(target_endian = "little") {
+            for x in slice { *x = x.swap_bytes(); }
+        }
+    }
+    fn from_slice_i64(slice: &mut [i64]) {
+        if cfg!(target_endian = "little") {
+            for x in slice { *x = x.swap_bytes(); }
+        }
+    }
+    fn from_slice_f32(slice: &mut [f32]) {
+        if cfg!(target_endian = "little") {
+            for x in slice { *x = f32::from_bits(x.to_bits().swap_bytes()); }
+        }
+    }
+    fn from_slice_f64(slice: &mut [f64]) {
+        if cfg!(target_endian = "little") {
+            for x in slice { *x = f64::from_bits(x.to_bits().swap_bytes()); }
+        }
+    }
+}
+
+impl ByteOrderExt for LittleEndian {
+    fn from_slice_u16(slice: &mut [u16]) {
+        if cfg!(target_endian = "big") {
+            for x in slice { *x = x.swap_bytes(); }
+        }
+    }
+    fn from_slice_i16(slice: &mut [i16]) {
+        if cfg!(target_endian = "big") {
+            for x in slice { *x = x.swap_bytes(); }
+        }
+    }
+    fn from_slice_u32(slice: &mut [u32]) {
+        if cfg!(target_endian = "big") {
+            for x in slice { *x = x.swap_bytes(); }
+        }
+    }
+    fn from_slice_i32(slice: &mut [i32]) {
+        if cfg!(target_endian = "big") {
+            for x in slice { *x = x.swap_bytes(); }
+        }
+    }
+    fn from_slice_u64(slice: &mut [u64]) {
+        if cfg!(target_endian = "big") {
+            for x in slice { *x = x.swap_bytes(); }
+        }
+    }
+    fn from_slice_i64(slice: &mut [i64]) {
+        if cfg!(target_endian = "big") {
+            for x in slice { *x = x.swap_bytes(); }
+        }
+    }
+    fn from_slice_f32(slice: &mut [f32]) {
+        if cfg!(target_endian = "big") {
+            for x in slice { *x = f32::from_bits(x.to_bits().swap_bytes()); }
+        }
+    }
+    fn from_slice_f64(slice: &mut [f64]) {
+        if cfg!(target_endian = "big") {
+            for x in slice { *x = f64::from_bits(x.to_bits().swap_bytes()); }
+        }
+    }
+}
+
+unsafe fn as_mut_bytes<T>(slice: &mut [T]) -> &mut [u8] {
+    slice::from_raw_parts_mut(
+        slice.as_mut_ptr() as *mut u8,
+        slice.len() * mem::size_of::<T>(),
+    )
+}
+
+unsafe fn as_bytes<T>(slice: &[T]) -> &[u8] {
+    slice::from_raw_parts(
+        slice.as_ptr() as *const u8,
+        slice.len() * mem::size_of::<T>(),
+    )
+}
 
 /// Extends `Read` with methods for reading numbers.
 ///
@@ -112,6 +260,214 @@ pub trait ReadBytesExt: io::Read + Sized {
         try!(read_full(self, &mut buf));
         Ok(<T as ByteOrder>::read_f64(&buf))
     }
+
+    /// Reads unsigned 16 bit integers from the underlying reader into `dst`.
+    ///
+    /// This fills `dst`'s own memory with a single read of
+    /// `dst.len() * 2` bytes, then fixes up the byte order of each element
+    /// in place, rather than issuing one read and one conversion call per
+    /// element. For the endianness that matches the host, the fix-up is a
+    /// no-op.
+    fn read_u16_into<T: ByteOrderExt>(&mut self, dst: &mut [u16]) -> io::Result<()> {
+        try!(read_full(self, unsafe { as_mut_bytes(dst) }));
+        <T as ByteOrderExt>::from_slice_u16(dst);
+        Ok(())
+    }
+
+    /// Reads signed 16 bit integers from the underlying reader into `dst`.
+    fn read_i16_into<T: ByteOrderExt>(&mut self, dst: &mut [i16]) -> io::Result<()> {
+        try!(read_full(self, unsafe { as_mut_bytes(dst) }));
+        <T as ByteOrderExt>::from_slice_i16(dst);
+        Ok(())
+    }
+
+    /// Reads unsigned 32 bit integers from the underlying reader into `dst`.
+    fn read_u32_into<T: ByteOrderExt>(&mut self, dst: &mut [u32]) -> io::Result<()> {
+        try!(read_full(self, unsafe { as_mut_bytes(dst) }));
+        <T as ByteOrderExt>::from_slice_u32(dst);
+        Ok(())
+    }
+
+    /// Reads signed 32 bit integers from the underlying reader into `dst`.
+    fn read_i32_into<T: ByteOrderExt>(&mut self, dst: &mut [i32]) -> io::Result<()> {
+        try!(read_full(self, unsafe { as_mut_bytes(dst) }));
+        <T as ByteOrderExt>::from_slice_i32(dst);
+        Ok(())
+    }
+
+    /// Reads unsigned 64 bit integers from the underlying reader into `dst`.
+    fn read_u64_into<T: ByteOrderExt>(&mut self, dst: &mut [u64]) -> io::Result<()> {
+        try!(read_full(self, unsafe { as_mut_bytes(dst) }));
+        <T as ByteOrderExt>::from_slice_u64(dst);
+        Ok(())
+    }
+
+    /// Reads signed 64 bit integers from the underlying reader into `dst`.
+    fn read_i64_into<T: ByteOrderExt>(&mut self, dst: &mut [i64]) -> io::Result<()> {
+        try!(read_full(self, unsafe { as_mut_bytes(dst) }));
+        <T as ByteOrderExt>::from_slice_i64(dst);
+        Ok(())
+    }
+
+    /// Reads IEEE754 single-precision (4 bytes) floating point numbers from
+    /// the underlying reader into `dst`.
+    fn read_f32_into<T: ByteOrderExt>(&mut self, dst: &mut [f32]) -> io::Result<()> {
+        try!(read_full(self, unsafe { as_mut_bytes(dst) }));
+        <T as ByteOrderExt>::from_slice_f32(dst);
+        Ok(())
+    }
+
+    /// Reads IEEE754 double-precision (8 bytes) floating point numbers from
+    /// the underlying reader into `dst`.
+    fn read_f64_into<T: ByteOrderExt>(&mut self, dst: &mut [f64]) -> io::Result<()> {
+        try!(read_full(self, unsafe { as_mut_bytes(dst) }));
+        <T as ByteOrderExt>::from_slice_f64(dst);
+        Ok(())
+    }
+
+    /// Reads a signed 32 bit fixed-point number with `frac_bits` fractional
+    /// bits, returning the equivalent `f64`.
+    ///
+    /// Container formats such as MP4/QuickTime store dimensions and
+    /// transform matrices this way (e.g. 16.16 and 2.30) rather than as
+    /// IEEE floats. The returned value is `raw as f64 / 2.0.powi(frac_bits)`,
+    /// which loses precision relative to the original fixed-point value
+    /// only in the same way any integer-to-float conversion does.
+    ///
+    /// `frac_bits` is typically well under 32 for real fixed-point formats;
+    /// unlike a raw `1 << frac_bits`, arbitrarily large values don't panic
+    /// here, they just scale the result towards zero.
+    fn read_fixed_i32<T: ByteOrder>(&mut self, frac_bits: u32) -> io::Result<f64> {
+        let raw = try!(self.read_i32::<T>());
+        Ok(raw as f64 / 2f64.powi(cmp::min(frac_bits, i32::max_value() as u32) as i32))
+    }
+
+    /// Reads an unsigned 32 bit fixed-point number with `frac_bits`
+    /// fractional bits, returning the equivalent `f64`.
+    fn read_fixed_u32<T: ByteOrder>(&mut self, frac_bits: u32) -> io::Result<f64> {
+        let raw = try!(self.read_u32::<T>());
+        Ok(raw as f64 / 2f64.powi(cmp::min(frac_bits, i32::max_value() as u32) as i32))
+    }
+
+    /// Reads a signed 16 bit fixed-point number with `frac_bits` fractional
+    /// bits, returning the equivalent `f64`.
+    fn read_fixed_i16<T: ByteOrder>(&mut self, frac_bits: u32) -> io::Result<f64> {
+        let raw = try!(self.read_i16::<T>());
+        Ok(raw as f64 / 2f64.powi(cmp::min(frac_bits, i32::max_value() as u32) as i32))
+    }
+
+    /// Reads an unsigned 16.16 fixed-point number, as used for the width
+    /// and height fields of an MP4 track header.
+    fn read_ufixed16_16<T: ByteOrder>(&mut self) -> io::Result<f64> {
+        self.read_fixed_u32::<T>(16)
+    }
+
+    /// Reads a signed 16.16 fixed-point number, as used in an MP4 movie
+    /// header's transform matrix.
+    fn read_fixed16_16<T: ByteOrder>(&mut self) -> io::Result<f64> {
+        self.read_fixed_i32::<T>(16)
+    }
+
+    /// Reads a signed 8.8 fixed-point number.
+    fn read_fixed8_8<T: ByteOrder>(&mut self) -> io::Result<f64> {
+        self.read_fixed_i16::<T>(8)
+    }
+
+    /// Reads a signed 2.30 fixed-point number, as used for the scale
+    /// entries of an MP4 movie header's transform matrix.
+    fn read_fixed2_30<T: ByteOrder>(&mut self) -> io::Result<f64> {
+        self.read_fixed_i32::<T>(30)
+    }
+
+    /// Reads an unsigned 16 bit integer from the underlying reader using a
+    /// byte order chosen at runtime.
+    fn read_u16_endian(&mut self, endian: Endianness) -> io::Result<u16> {
+        match endian {
+            Endianness::Big => self.read_u16::<BigEndian>(),
+            Endianness::Little => self.read_u16::<LittleEndian>(),
+        }
+    }
+
+    /// Reads a signed 16 bit integer from the underlying reader using a
+    /// byte order chosen at runtime.
+    fn read_i16_endian(&mut self, endian: Endianness) -> io::Result<i16> {
+        match endian {
+            Endianness::Big => self.read_i16::<BigEndian>(),
+            Endianness::Little => self.read_i16::<LittleEndian>(),
+        }
+    }
+
+    /// Reads an unsigned 32 bit integer from the underlying reader using a
+    /// byte order chosen at runtime.
+    fn read_u32_endian(&mut self, endian: Endianness) -> io::Result<u32> {
+        match endian {
+            Endianness::Big => self.read_u32::<BigEndian>(),
+            Endianness::Little => self.read_u32::<LittleEndian>(),
+        }
+    }
+
+    /// Reads a signed 32 bit integer from the underlying reader using a
+    /// byte order chosen at runtime.
+    fn read_i32_endian(&mut self, endian: Endianness) -> io::Result<i32> {
+        match endian {
+            Endianness::Big => self.read_i32::<BigEndian>(),
+            Endianness::Little => self.read_i32::<LittleEndian>(),
+        }
+    }
+
+    /// Reads an unsigned 64 bit integer from the underlying reader using a
+    /// byte order chosen at runtime.
+    fn read_u64_endian(&mut self, endian: Endianness) -> io::Result<u64> {
+        match endian {
+            Endianness::Big => self.read_u64::<BigEndian>(),
+            Endianness::Little => self.read_u64::<LittleEndian>(),
+        }
+    }
+
+    /// Reads a signed 64 bit integer from the underlying reader using a
+    /// byte order chosen at runtime.
+    fn read_i64_endian(&mut self, endian: Endianness) -> io::Result<i64> {
+        match endian {
+            Endianness::Big => self.read_i64::<BigEndian>(),
+            Endianness::Little => self.read_i64::<LittleEndian>(),
+        }
+    }
+
+    /// Reads an unsigned n-bytes integer from the underlying reader using a
+    /// byte order chosen at runtime.
+    fn read_uint_endian(&mut self, endian: Endianness, nbytes: usize) -> io::Result<u64> {
+        match endian {
+            Endianness::Big => self.read_uint::<BigEndian>(nbytes),
+            Endianness::Little => self.read_uint::<LittleEndian>(nbytes),
+        }
+    }
+
+    /// Reads a signed n-bytes integer from the underlying reader using a
+    /// byte order chosen at runtime.
+    fn read_int_endian(&mut self, endian: Endianness, nbytes: usize) -> io::Result<i64> {
+        match endian {
+            Endianness::Big => self.read_int::<BigEndian>(nbytes),
+            Endianness::Little => self.read_int::<LittleEndian>(nbytes),
+        }
+    }
+
+    /// Reads a IEEE754 single-precision (4 bytes) floating point number
+    /// from the underlying reader using a byte order chosen at runtime.
+    fn read_f32_endian(&mut self, endian: Endianness) -> io::Result<f32> {
+        match endian {
+            Endianness::Big => self.read_f32::<BigEndian>(),
+            Endianness::Little => self.read_f32::<LittleEndian>(),
+        }
+    }
+
+    /// Reads a IEEE754 double-precision (8 bytes) floating point number
+    /// from the underlying reader using a byte order chosen at runtime.
+    fn read_f64_endian(&mut self, endian: Endianness) -> io::Result<f64> {
+        match endian {
+            Endianness::Big => self.read_f64::<BigEndian>(),
+            Endianness::Little => self.read_f64::<LittleEndian>(),
+        }
+    }
 }
 
 /// All types that implement `Read` get methods defined in `ReadBytesExt`
@@ -218,8 +574,722 @@ pub trait WriteBytesExt: io::Write + Sized {
         <T as ByteOrder>::write_f64(&mut buf, n);
         self.write_all(&buf)
     }
+
+    /// Writes unsigned 16 bit integers from `src` to the underlying writer.
+    ///
+    /// This clones `src`, fixes up the byte order of the clone in place,
+    /// and issues a single `write_all`, rather than one conversion call
+    /// and one `write` per element.
+    fn write_u16_from<T: ByteOrderExt>(&mut self, src: &[u16]) -> io::Result<()> {
+        let mut buf = src.to_vec();
+        <T as ByteOrderExt>::from_slice_u16(&mut buf);
+        self.write_all(unsafe { as_bytes(&buf) })
+    }
+
+    /// Writes signed 16 bit integers from `src` to the underlying writer.
+    fn write_i16_from<T: ByteOrderExt>(&mut self, src: &[i16]) -> io::Result<()> {
+        let mut buf = src.to_vec();
+        <T as ByteOrderExt>::from_slice_i16(&mut buf);
+        self.write_all(unsafe { as_bytes(&buf) })
+    }
+
+    /// Writes unsigned 32 bit integers from `src` to the underlying writer.
+    fn write_u32_from<T: ByteOrderExt>(&mut self, src: &[u32]) -> io::Result<()> {
+        let mut buf = src.to_vec();
+        <T as ByteOrderExt>::from_slice_u32(&mut buf);
+        self.write_all(unsafe { as_bytes(&buf) })
+    }
+
+    /// Writes signed 32 bit integers from `src` to the underlying writer.
+    fn write_i32_from<T: ByteOrderExt>(&mut self, src: &[i32]) -> io::Result<()> {
+        let mut buf = src.to_vec();
+        <T as ByteOrderExt>::from_slice_i32(&mut buf);
+        self.write_all(unsafe { as_bytes(&buf) })
+    }
+
+    /// Writes unsigned 64 bit integers from `src` to the underlying writer.
+    fn write_u64_from<T: ByteOrderExt>(&mut self, src: &[u64]) -> io::Result<()> {
+        let mut buf = src.to_vec();
+        <T as ByteOrderExt>::from_slice_u64(&mut buf);
+        self.write_all(unsafe { as_bytes(&buf) })
+    }
+
+    /// Writes signed 64 bit integers from `src` to the underlying writer.
+    fn write_i64_from<T: ByteOrderExt>(&mut self, src: &[i64]) -> io::Result<()> {
+        let mut buf = src.to_vec();
+        <T as ByteOrderExt>::from_slice_i64(&mut buf);
+        self.write_all(unsafe { as_bytes(&buf) })
+    }
+
+    /// Writes IEEE754 single-precision (4 bytes) floating point numbers
+    /// from `src` to the underlying writer.
+    fn write_f32_from<T: ByteOrderExt>(&mut self, src: &[f32]) -> io::Result<()> {
+        let mut buf = src.to_vec();
+        <T as ByteOrderExt>::from_slice_f32(&mut buf);
+        self.write_all(unsafe { as_bytes(&buf) })
+    }
+
+    /// Writes IEEE754 double-precision (8 bytes) floating point numbers
+    /// from `src` to the underlying writer.
+    fn write_f64_from<T: ByteOrderExt>(&mut self, src: &[f64]) -> io::Result<()> {
+        let mut buf = src.to_vec();
+        <T as ByteOrderExt>::from_slice_f64(&mut buf);
+        self.write_all(unsafe { as_bytes(&buf) })
+    }
+
+    /// Writes `n` as a signed 32 bit fixed-point number with `frac_bits`
+    /// fractional bits.
+    ///
+    /// `n` is multiplied by `2.0.powi(frac_bits)`, rounded to the nearest
+    /// integer, and clamped to the range of `i32` before being serialized,
+    /// so values that don't fit the target fixed-point format saturate
+    /// rather than wrap. `frac_bits` is not bounds-checked, but unlike a raw
+    /// `1 << frac_bits` it cannot panic or wrap for any `u32` value.
+    fn write_fixed_i32<T: ByteOrder>(&mut self, n: f64, frac_bits: u32) -> io::Result<()> {
+        let scaled = (n * 2f64.powi(cmp::min(frac_bits, i32::max_value() as u32) as i32)).round();
+        let raw = if scaled <= i32::min_value() as f64 {
+            i32::min_value()
+        } else if scaled >= i32::max_value() as f64 {
+            i32::max_value()
+        } else {
+            scaled as i32
+        };
+        self.write_i32::<T>(raw)
+    }
+
+    /// Writes `n` as an unsigned 32 bit fixed-point number with
+    /// `frac_bits` fractional bits, rounding and clamping as
+    /// `write_fixed_i32` does.
+    fn write_fixed_u32<T: ByteOrder>(&mut self, n: f64, frac_bits: u32) -> io::Result<()> {
+        let scaled = (n * 2f64.powi(cmp::min(frac_bits, i32::max_value() as u32) as i32)).round();
+        let raw = if scaled <= u32::min_value() as f64 {
+            u32::min_value()
+        } else if scaled >= u32::max_value() as f64 {
+            u32::max_value()
+        } else {
+            scaled as u32
+        };
+        self.write_u32::<T>(raw)
+    }
+
+    /// Writes `n` as a signed 16 bit fixed-point number with `frac_bits`
+    /// fractional bits, rounding and clamping as `write_fixed_i32` does.
+    fn write_fixed_i16<T: ByteOrder>(&mut self, n: f64, frac_bits: u32) -> io::Result<()> {
+        let scaled = (n * 2f64.powi(cmp::min(frac_bits, i32::max_value() as u32) as i32)).round();
+        let raw = if scaled <= i16::min_value() as f64 {
+            i16::min_value()
+        } else if scaled >= i16::max_value() as f64 {
+            i16::max_value()
+        } else {
+            scaled as i16
+        };
+        self.write_i16::<T>(raw)
+    }
+
+    /// Writes `n` as an unsigned 16.16 fixed-point number.
+    fn write_ufixed16_16<T: ByteOrder>(&mut self, n: f64) -> io::Result<()> {
+        self.write_fixed_u32::<T>(n, 16)
+    }
+
+    /// Writes `n` as a signed 16.16 fixed-point number.
+    fn write_fixed16_16<T: ByteOrder>(&mut self, n: f64) -> io::Result<()> {
+        self.write_fixed_i32::<T>(n, 16)
+    }
+
+    /// Writes `n` as a signed 8.8 fixed-point number.
+    fn write_fixed8_8<T: ByteOrder>(&mut self, n: f64) -> io::Result<()> {
+        self.write_fixed_i16::<T>(n, 8)
+    }
+
+    /// Writes `n` as a signed 2.30 fixed-point number.
+    fn write_fixed2_30<T: ByteOrder>(&mut self, n: f64) -> io::Result<()> {
+        self.write_fixed_i32::<T>(n, 30)
+    }
+
+    /// Writes an unsigned 16 bit integer to the underlying writer using a
+    /// byte order chosen at runtime.
+    fn write_u16_endian(&mut self, n: u16, endian: Endianness) -> io::Result<()> {
+        match endian {
+            Endianness::Big => self.write_u16::<BigEndian>(n),
+            Endianness::Little => self.write_u16::<LittleEndian>(n),
+        }
+    }
+
+    /// Writes a signed 16 bit integer to the underlying writer using a
+    /// byte order chosen at runtime.
+    fn write_i16_endian(&mut self, n: i16, endian: Endianness) -> io::Result<()> {
+        match endian {
+            Endianness::Big => self.write_i16::<BigEndian>(n),
+            Endianness::Little => self.write_i16::<LittleEndian>(n),
+        }
+    }
+
+    /// Writes an unsigned 32 bit integer to the underlying writer using a
+    /// byte order chosen at runtime.
+    fn write_u32_endian(&mut self, n: u32, endian: Endianness) -> io::Result<()> {
+        match endian {
+            Endianness::Big => self.write_u32::<BigEndian>(n),
+            Endianness::Little => self.write_u32::<LittleEndian>(n),
+        }
+    }
+
+    /// Writes a signed 32 bit integer to the underlying writer using a
+    /// byte order chosen at runtime.
+    fn write_i32_endian(&mut self, n: i32, endian: Endianness) -> io::Result<()> {
+        match endian {
+            Endianness::Big => self.write_i32::<BigEndian>(n),
+            Endianness::Little => self.write_i32::<LittleEndian>(n),
+        }
+    }
+
+    /// Writes an unsigned 64 bit integer to the underlying writer using a
+    /// byte order chosen at runtime.
+    fn write_u64_endian(&mut self, n: u64, endian: Endianness) -> io::Result<()> {
+        match endian {
+            Endianness::Big => self.write_u64::<BigEndian>(n),
+            Endianness::Little => self.write_u64::<LittleEndian>(n),
+        }
+    }
+
+    /// Writes a signed 64 bit integer to the underlying writer using a
+    /// byte order chosen at runtime.
+    fn write_i64_endian(&mut self, n: i64, endian: Endianness) -> io::Result<()> {
+        match endian {
+            Endianness::Big => self.write_i64::<BigEndian>(n),
+            Endianness::Little => self.write_i64::<LittleEndian>(n),
+        }
+    }
+
+    /// Writes a IEEE754 single-precision (4 bytes) floating point number
+    /// to the underlying writer using a byte order chosen at runtime.
+    fn write_f32_endian(&mut self, n: f32, endian: Endianness) -> io::Result<()> {
+        match endian {
+            Endianness::Big => self.write_f32::<BigEndian>(n),
+            Endianness::Little => self.write_f32::<LittleEndian>(n),
+        }
+    }
+
+    /// Writes a IEEE754 double-precision (8 bytes) floating point number
+    /// to the underlying writer using a byte order chosen at runtime.
+    fn write_f64_endian(&mut self, n: f64, endian: Endianness) -> io::Result<()> {
+        match endian {
+            Endianness::Big => self.write_f64::<BigEndian>(n),
+            Endianness::Little => self.write_f64::<LittleEndian>(n),
+        }
+    }
 }
 
 /// All types that implement `Write` get methods defined in `WriteBytesExt`
 /// for free.
 impl<W: io::Write> WriteBytesExt for W {}
+
+/// A type that can read bytes from a fixed position without a mutable
+/// cursor.
+///
+/// Unlike `Read`, `read_at` takes `&self`, so the same value (e.g. a `File`)
+/// can service positioned reads from multiple threads concurrently.
+pub trait ReadAt {
+    /// Reads some bytes starting at `pos` into `buf`, returning the number
+    /// of bytes read.
+    fn read_at(&self, pos: u64, buf: &mut [u8]) -> io::Result<usize>;
+}
+
+/// A type that can write bytes at a fixed position without a mutable
+/// cursor.
+pub trait WriteAt {
+    /// Writes some bytes from `buf` starting at `pos`, returning the number
+    /// of bytes written.
+    fn write_at(&self, pos: u64, buf: &[u8]) -> io::Result<usize>;
+}
+
+#[cfg(unix)]
+impl ReadAt for ::std::fs::File {
+    fn read_at(&self, pos: u64, buf: &mut [u8]) -> io::Result<usize> {
+        use std::os::unix::fs::FileExt;
+        FileExt::read_at(self, buf, pos)
+    }
+}
+
+#[cfg(windows)]
+impl ReadAt for ::std::fs::File {
+    fn read_at(&self, pos: u64, buf: &mut [u8]) -> io::Result<usize> {
+        use std::os::windows::fs::FileExt;
+        FileExt::seek_read(self, buf, pos)
+    }
+}
+
+#[cfg(unix)]
+impl WriteAt for ::std::fs::File {
+    fn write_at(&self, pos: u64, buf: &[u8]) -> io::Result<usize> {
+        use std::os::unix::fs::FileExt;
+        FileExt::write_at(self, buf, pos)
+    }
+}
+
+#[cfg(windows)]
+impl WriteAt for ::std::fs::File {
+    fn write_at(&self, pos: u64, buf: &[u8]) -> io::Result<usize> {
+        use std::os::windows::fs::FileExt;
+        FileExt::seek_write(self, buf, pos)
+    }
+}
+
+fn read_exact_at<R: ReadAt + ?Sized>(
+    rdr: &R,
+    mut pos: u64,
+    buf: &mut [u8],
+) -> io::Result<()> {
+    let mut n = 0usize;
+    while n < buf.len() {
+        let nread = try!(rdr.read_at(pos, &mut buf[n..]));
+        if nread == 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                "failed to fill whole buffer",
+            ));
+        }
+        n += nread;
+        pos += nread as u64;
+    }
+    Ok(())
+}
+
+fn write_all_at<W: WriteAt + ?Sized>(
+    wtr: &W,
+    mut pos: u64,
+    buf: &[u8],
+) -> io::Result<()> {
+    let mut n = 0usize;
+    while n < buf.len() {
+        let nwritten = try!(wtr.write_at(pos, &buf[n..]));
+        if nwritten == 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::WriteZero,
+                "failed to write whole buffer",
+            ));
+        }
+        n += nwritten;
+        pos += nwritten as u64;
+    }
+    Ok(())
+}
+
+/// Extends `ReadAt` with methods for reading numbers at a given offset.
+///
+/// This parallels `ReadBytesExt`, except every method takes an explicit
+/// `pos` instead of advancing an internal cursor, so `&self` suffices and
+/// no seek is required.
+///
+/// # Examples
+///
+/// Read an unsigned 32 bit big-endian integer from byte offset 4 of a
+/// `File`, without seeking:
+///
+/// ```rust,no_run
+/// use std::fs::File;
+/// use byteorder::{BigEndian, ReadBytesAtExt};
+///
+/// let f = File::open("box.mp4").unwrap();
+/// let size = f.read_u32_at::<BigEndian>(4).unwrap();
+/// ```
+pub trait ReadBytesAtExt: ReadAt {
+    /// Reads an unsigned 8 bit integer from `pos`.
+    ///
+    /// Note that since this reads a single byte, no byte order conversions
+    /// are used. It is included for completeness.
+    fn read_u8_at(&self, pos: u64) -> io::Result<u8> {
+        let mut buf = [0; 1];
+        try!(read_exact_at(self, pos, &mut buf));
+        Ok(buf[0])
+    }
+
+    /// Reads a signed 8 bit integer from `pos`.
+    ///
+    /// Note that since this reads a single byte, no byte order conversions
+    /// are used. It is included for completeness.
+    fn read_i8_at(&self, pos: u64) -> io::Result<i8> {
+        let mut buf = [0; 1];
+        try!(read_exact_at(self, pos, &mut buf));
+        Ok(buf[0] as i8)
+    }
+
+    /// Reads an unsigned 16 bit integer from `pos`.
+    fn read_u16_at<T: ByteOrder>(&self, pos: u64) -> io::Result<u16> {
+        let mut buf = [0; 2];
+        try!(read_exact_at(self, pos, &mut buf));
+        Ok(<T as ByteOrder>::read_u16(&buf))
+    }
+
+    /// Reads a signed 16 bit integer from `pos`.
+    fn read_i16_at<T: ByteOrder>(&self, pos: u64) -> io::Result<i16> {
+        let mut buf = [0; 2];
+        try!(read_exact_at(self, pos, &mut buf));
+        Ok(<T as ByteOrder>::read_i16(&buf))
+    }
+
+    /// Reads an unsigned 32 bit integer from `pos`.
+    fn read_u32_at<T: ByteOrder>(&self, pos: u64) -> io::Result<u32> {
+        let mut buf = [0; 4];
+        try!(read_exact_at(self, pos, &mut buf));
+        Ok(<T as ByteOrder>::read_u32(&buf))
+    }
+
+    /// Reads a signed 32 bit integer from `pos`.
+    fn read_i32_at<T: ByteOrder>(&self, pos: u64) -> io::Result<i32> {
+        let mut buf = [0; 4];
+        try!(read_exact_at(self, pos, &mut buf));
+        Ok(<T as ByteOrder>::read_i32(&buf))
+    }
+
+    /// Reads an unsigned 64 bit integer from `pos`.
+    fn read_u64_at<T: ByteOrder>(&self, pos: u64) -> io::Result<u64> {
+        let mut buf = [0; 8];
+        try!(read_exact_at(self, pos, &mut buf));
+        Ok(<T as ByteOrder>::read_u64(&buf))
+    }
+
+    /// Reads a signed 64 bit integer from `pos`.
+    fn read_i64_at<T: ByteOrder>(&self, pos: u64) -> io::Result<i64> {
+        let mut buf = [0; 8];
+        try!(read_exact_at(self, pos, &mut buf));
+        Ok(<T as ByteOrder>::read_i64(&buf))
+    }
+
+    /// Reads an unsigned n-bytes integer from `pos`.
+    fn read_uint_at<T: ByteOrder>(&self, pos: u64, nbytes: usize) -> io::Result<u64> {
+        let mut buf = [0; 8];
+        try!(read_exact_at(self, pos, &mut buf[0..nbytes]));
+        Ok(<T as ByteOrder>::read_uint(&buf, nbytes))
+    }
+
+    /// Reads a signed n-bytes integer from `pos`.
+    fn read_int_at<T: ByteOrder>(&self, pos: u64, nbytes: usize) -> io::Result<i64> {
+        let mut buf = [0; 8];
+        try!(read_exact_at(self, pos, &mut buf[0..nbytes]));
+        Ok(<T as ByteOrder>::read_int(&buf, nbytes))
+    }
+
+    /// Reads a IEEE754 single-precision (4 bytes) floating point number
+    /// from `pos`.
+    fn read_f32_at<T: ByteOrder>(&self, pos: u64) -> io::Result<f32> {
+        let mut buf = [0; 4];
+        try!(read_exact_at(self, pos, &mut buf));
+        Ok(<T as ByteOrder>::read_f32(&buf))
+    }
+
+    /// Reads a IEEE754 double-precision (8 bytes) floating point number
+    /// from `pos`.
+    fn read_f64_at<T: ByteOrder>(&self, pos: u64) -> io::Result<f64> {
+        let mut buf = [0; 8];
+        try!(read_exact_at(self, pos, &mut buf));
+        Ok(<T as ByteOrder>::read_f64(&buf))
+    }
+}
+
+/// All types that implement `ReadAt` get methods defined in
+/// `ReadBytesAtExt` for free.
+impl<R: ReadAt> ReadBytesAtExt for R {}
+
+/// Extends `WriteAt` with methods for writing numbers at a given offset.
+///
+/// This parallels `WriteBytesExt`, except every method takes an explicit
+/// `pos` instead of advancing an internal cursor.
+pub trait WriteBytesAtExt: WriteAt {
+    /// Writes an unsigned 8 bit integer to `pos`.
+    ///
+    /// Note that since this writes a single byte, no byte order conversions
+    /// are used. It is included for completeness.
+    fn write_u8_at(&self, pos: u64, n: u8) -> io::Result<()> {
+        write_all_at(self, pos, &[n])
+    }
+
+    /// Writes a signed 8 bit integer to `pos`.
+    ///
+    /// Note that since this writes a single byte, no byte order conversions
+    /// are used. It is included for completeness.
+    fn write_i8_at(&self, pos: u64, n: i8) -> io::Result<()> {
+        write_all_at(self, pos, &[n as u8])
+    }
+
+    /// Writes an unsigned 16 bit integer to `pos`.
+    fn write_u16_at<T: ByteOrder>(&self, pos: u64, n: u16) -> io::Result<()> {
+        let mut buf = [0; 2];
+        <T as ByteOrder>::write_u16(&mut buf, n);
+        write_all_at(self, pos, &buf)
+    }
+
+    /// Writes a signed 16 bit integer to `pos`.
+    fn write_i16_at<T: ByteOrder>(&self, pos: u64, n: i16) -> io::Result<()> {
+        let mut buf = [0; 2];
+        <T as ByteOrder>::write_i16(&mut buf, n);
+        write_all_at(self, pos, &buf)
+    }
+
+    /// Writes an unsigned 32 bit integer to `pos`.
+    fn write_u32_at<T: ByteOrder>(&self, pos: u64, n: u32) -> io::Result<()> {
+        let mut buf = [0; 4];
+        <T as ByteOrder>::write_u32(&mut buf, n);
+        write_all_at(self, pos, &buf)
+    }
+
+    /// Writes a signed 32 bit integer to `pos`.
+    fn write_i32_at<T: ByteOrder>(&self, pos: u64, n: i32) -> io::Result<()> {
+        let mut buf = [0; 4];
+        <T as ByteOrder>::write_i32(&mut buf, n);
+        write_all_at(self, pos, &buf)
+    }
+
+    /// Writes an unsigned 64 bit integer to `pos`.
+    fn write_u64_at<T: ByteOrder>(&self, pos: u64, n: u64) -> io::Result<()> {
+        let mut buf = [0; 8];
+        <T as ByteOrder>::write_u64(&mut buf, n);
+        write_all_at(self, pos, &buf)
+    }
+
+    /// Writes a signed 64 bit integer to `pos`.
+    fn write_i64_at<T: ByteOrder>(&self, pos: u64, n: i64) -> io::Result<()> {
+        let mut buf = [0; 8];
+        <T as ByteOrder>::write_i64(&mut buf, n);
+        write_all_at(self, pos, &buf)
+    }
+
+    /// Writes a IEEE754 single-precision (4 bytes) floating point number
+    /// to `pos`.
+    fn write_f32_at<T: ByteOrder>(&self, pos: u64, n: f32) -> io::Result<()> {
+        let mut buf = [0; 4];
+        <T as ByteOrder>::write_f32(&mut buf, n);
+        write_all_at(self, pos, &buf)
+    }
+
+    /// Writes a IEEE754 double-precision (8 bytes) floating point number
+    /// to `pos`.
+    fn write_f64_at<T: ByteOrder>(&self, pos: u64, n: f64) -> io::Result<()> {
+        let mut buf = [0; 8];
+        <T as ByteOrder>::write_f64(&mut buf, n);
+        write_all_at(self, pos, &buf)
+    }
+}
+
+/// All types that implement `WriteAt` get methods defined in
+/// `WriteBytesAtExt` for free.
+impl<W: WriteAt> WriteBytesAtExt for W {}
+
+#[cfg(test)]
+mod test {
+    use std::cell::RefCell;
+    use std::cmp;
+    use std::io;
+    use std::io::Cursor;
+
+    use super::{
+        BigEndian, Endianness, LittleEndian, ReadAt, ReadBytesAtExt, ReadBytesExt, WriteAt,
+        WriteBytesAtExt, WriteBytesExt,
+    };
+
+    /// An in-memory `ReadAt`/`WriteAt` fixture, backed by a growable buffer
+    /// behind a `RefCell` so both traits can be implemented on `&self`.
+    struct MemAt(RefCell<Vec<u8>>);
+
+    impl MemAt {
+        fn new(len: usize) -> MemAt {
+            MemAt(RefCell::new(vec![0; len]))
+        }
+    }
+
+    impl ReadAt for MemAt {
+        fn read_at(&self, pos: u64, buf: &mut [u8]) -> io::Result<usize> {
+            let inner = self.0.borrow();
+            let pos = pos as usize;
+            if pos >= inner.len() {
+                return Ok(0);
+            }
+            let n = cmp::min(buf.len(), inner.len() - pos);
+            buf[..n].copy_from_slice(&inner[pos..pos + n]);
+            Ok(n)
+        }
+    }
+
+    impl WriteAt for MemAt {
+        fn write_at(&self, pos: u64, buf: &[u8]) -> io::Result<usize> {
+            let mut inner = self.0.borrow_mut();
+            let pos = pos as usize;
+            if pos + buf.len() > inner.len() {
+                inner.resize(pos + buf.len(), 0);
+            }
+            inner[pos..pos + buf.len()].copy_from_slice(buf);
+            Ok(buf.len())
+        }
+    }
+
+    #[test]
+    fn write_u16_at_read_u16_at_round_trip() {
+        let mem = MemAt::new(8);
+        mem.write_u16_at::<BigEndian>(2, 517).unwrap();
+        assert_eq!(517, mem.read_u16_at::<BigEndian>(2).unwrap());
+    }
+
+    #[test]
+    fn write_f64_at_read_f64_at_round_trip() {
+        let mem = MemAt::new(8);
+        mem.write_f64_at::<LittleEndian>(0, 12.5).unwrap();
+        assert_eq!(12.5, mem.read_f64_at::<LittleEndian>(0).unwrap());
+    }
+
+    #[test]
+    fn read_u16_at_errors_instead_of_hanging_on_eof() {
+        let mem = MemAt::new(1);
+        match mem.read_u16_at::<BigEndian>(0) {
+            Err(ref e) if e.kind() == io::ErrorKind::UnexpectedEof => {}
+            other => panic!("expected UnexpectedEof, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn fixed16_16_round_trip() {
+        let mut buf = vec![];
+        buf.write_fixed16_16::<BigEndian>(12.5).unwrap();
+        let mut rdr = Cursor::new(buf);
+        assert_eq!(12.5, rdr.read_fixed16_16::<BigEndian>().unwrap());
+    }
+
+    #[test]
+    fn ufixed16_16_round_trip() {
+        let mut buf = vec![];
+        buf.write_ufixed16_16::<LittleEndian>(640.0).unwrap();
+        let mut rdr = Cursor::new(buf);
+        assert_eq!(640.0, rdr.read_ufixed16_16::<LittleEndian>().unwrap());
+    }
+
+    #[test]
+    fn fixed8_8_round_trip() {
+        let mut buf = vec![];
+        buf.write_fixed8_8::<BigEndian>(-3.5).unwrap();
+        let mut rdr = Cursor::new(buf);
+        assert_eq!(-3.5, rdr.read_fixed8_8::<BigEndian>().unwrap());
+    }
+
+    #[test]
+    fn fixed2_30_round_trip() {
+        let mut buf = vec![];
+        buf.write_fixed2_30::<BigEndian>(1.0).unwrap();
+        let mut rdr = Cursor::new(buf);
+        assert_eq!(1.0, rdr.read_fixed2_30::<BigEndian>().unwrap());
+    }
+
+    #[test]
+    fn write_fixed_i32_saturates_at_max() {
+        let mut buf = vec![];
+        buf.write_fixed_i32::<BigEndian>(1e12, 16).unwrap();
+        let mut rdr = Cursor::new(buf);
+        assert_eq!(i32::max_value(), rdr.read_i32::<BigEndian>().unwrap());
+    }
+
+    #[test]
+    fn write_fixed_i32_saturates_at_min() {
+        let mut buf = vec![];
+        buf.write_fixed_i32::<BigEndian>(-1e12, 16).unwrap();
+        let mut rdr = Cursor::new(buf);
+        assert_eq!(i32::min_value(), rdr.read_i32::<BigEndian>().unwrap());
+    }
+
+    #[test]
+    fn write_fixed_u32_saturates_at_zero_for_negative_input() {
+        let mut buf = vec![];
+        buf.write_fixed_u32::<BigEndian>(-5.0, 16).unwrap();
+        let mut rdr = Cursor::new(buf);
+        assert_eq!(0, rdr.read_u32::<BigEndian>().unwrap());
+    }
+
+    #[test]
+    fn write_fixed_i16_rounds_to_nearest() {
+        let mut buf = vec![];
+        buf.write_fixed_i16::<BigEndian>(1.4 / 256.0, 8).unwrap();
+        let mut rdr = Cursor::new(buf);
+        assert_eq!(1.0 / 256.0, rdr.read_fixed_i16::<BigEndian>(8).unwrap());
+    }
+
+    #[test]
+    fn fixed_point_methods_dont_panic_on_huge_frac_bits() {
+        let mut buf = vec![];
+        buf.write_fixed_i32::<BigEndian>(1.0, 100).unwrap();
+        let mut rdr = Cursor::new(buf);
+        let n = rdr.read_fixed_i32::<BigEndian>(100).unwrap();
+        assert!(n.is_finite() && n.abs() < 1.0);
+    }
+
+    #[test]
+    fn write_u16_from_read_u16_into_round_trip_big_endian() {
+        let src = [1u16, 2, 258, 65535];
+        let mut buf = vec![];
+        buf.write_u16_from::<BigEndian>(&src).unwrap();
+        let mut dst = [0u16; 4];
+        Cursor::new(buf).read_u16_into::<BigEndian>(&mut dst).unwrap();
+        assert_eq!(src, dst);
+    }
+
+    #[test]
+    fn write_u16_from_read_u16_into_round_trip_little_endian() {
+        let src = [1u16, 2, 258, 65535];
+        let mut buf = vec![];
+        buf.write_u16_from::<LittleEndian>(&src).unwrap();
+        let mut dst = [0u16; 4];
+        Cursor::new(buf).read_u16_into::<LittleEndian>(&mut dst).unwrap();
+        assert_eq!(src, dst);
+    }
+
+    #[test]
+    fn write_f32_from_read_f32_into_round_trip() {
+        let src = [1.5f32, -2.25, 0.0, 12345.678];
+        let mut buf = vec![];
+        buf.write_f32_from::<BigEndian>(&src).unwrap();
+        let mut dst = [0f32; 4];
+        Cursor::new(buf).read_f32_into::<BigEndian>(&mut dst).unwrap();
+        assert_eq!(src, dst);
+    }
+
+    #[test]
+    fn u16_endian_round_trip() {
+        for &endian in &[Endianness::Big, Endianness::Little] {
+            let mut buf = vec![];
+            buf.write_u16_endian(517, endian).unwrap();
+            let mut rdr = Cursor::new(buf);
+            assert_eq!(517, rdr.read_u16_endian(endian).unwrap());
+        }
+    }
+
+    #[test]
+    fn i32_endian_round_trip() {
+        for &endian in &[Endianness::Big, Endianness::Little] {
+            let mut buf = vec![];
+            buf.write_i32_endian(-123456, endian).unwrap();
+            let mut rdr = Cursor::new(buf);
+            assert_eq!(-123456, rdr.read_i32_endian(endian).unwrap());
+        }
+    }
+
+    #[test]
+    fn u64_endian_round_trip() {
+        for &endian in &[Endianness::Big, Endianness::Little] {
+            let mut buf = vec![];
+            buf.write_u64_endian(0x0102030405060708, endian).unwrap();
+            let mut rdr = Cursor::new(buf);
+            assert_eq!(0x0102030405060708, rdr.read_u64_endian(endian).unwrap());
+        }
+    }
+
+    #[test]
+    fn f64_endian_round_trip() {
+        for &endian in &[Endianness::Big, Endianness::Little] {
+            let mut buf = vec![];
+            buf.write_f64_endian(12.5, endian).unwrap();
+            let mut rdr = Cursor::new(buf);
+            assert_eq!(12.5, rdr.read_f64_endian(endian).unwrap());
+        }
+    }
+
+    #[test]
+    fn native_and_network_are_consistent_with_big_and_little() {
+        assert_eq!(Endianness::Big, Endianness::NETWORK);
+        assert!(Endianness::NATIVE == Endianness::Big || Endianness::NATIVE == Endianness::Little);
+    }
+}